@@ -1,24 +1,167 @@
 use tao::{
+    dpi::{LogicalPosition, LogicalSize, PhysicalPosition},
     event::{Event, WindowEvent},
-    event_loop::{ControlFlow, EventLoopBuilder},
-    window::WindowBuilder,
+    event_loop::{ControlFlow, EventLoopBuilder, EventLoopWindowTarget},
+    monitor::MonitorHandle,
+    window::{Fullscreen, WindowBuilder},
 };
+use wry::http::{Request, Response};
 use wry::WebViewBuilder;
+use std::borrow::Cow;
 use std::env;
+use std::fs;
+
+use include_dir::{include_dir, Dir};
+
+#[path = "../display_config.rs"]
+mod display_config;
+use display_config::{DisplayConfig, MonitorTarget};
+
+impl DisplayConfig {
+    /// Accepts either inline JSON (`{"fullscreen": true, ...}`) or a path to
+    /// a JSON file containing the same shape.
+    fn load(arg: &str) -> DisplayConfig {
+        let json = if arg.trim_start().starts_with('{') {
+            Cow::Borrowed(arg)
+        } else {
+            match fs::read_to_string(arg) {
+                Ok(contents) => Cow::Owned(contents),
+                Err(e) => {
+                    eprintln!("Failed to read display config file '{}': {}", arg, e);
+                    return DisplayConfig::default();
+                }
+            }
+        };
+
+        serde_json::from_str(&json).unwrap_or_else(|e| {
+            eprintln!("Failed to parse display config: {}", e);
+            DisplayConfig::default()
+        })
+    }
+
+    fn resolve_monitor(&self, event_loop: &EventLoopWindowTarget<()>) -> Option<MonitorHandle> {
+        let target = self.monitor.as_ref()?;
+        let monitors: Vec<MonitorHandle> = event_loop.available_monitors().collect();
+        match target {
+            MonitorTarget::Index(index) => monitors.get(*index).cloned(),
+            MonitorTarget::Name(name) => monitors
+                .into_iter()
+                .find(|m| m.name().as_deref() == Some(name.as_str())),
+        }
+    }
+}
+
+/// Display assets bundled into the binary at compile time, served over the
+/// `proassist://` custom protocol so the display window never depends on a
+/// dev server or a guessed on-disk path.
+static DISPLAY_ASSETS: Dir = include_dir!("$CARGO_MANIFEST_DIR/assets/display");
+
+const PROASSIST_PROTOCOL: &str = "proassist";
+
+/// Strips the `proassist://display/` prefix from a requested URI, looks the
+/// remainder up in the embedded asset bundle, and falls back to
+/// `index.html` for unknown routes (so client-side routes still resolve).
+fn serve_asset(uri_path: &str) -> Response<Cow<'static, [u8]>> {
+    let relative_path = uri_path.trim_start_matches('/');
+    let relative_path = if relative_path.is_empty() {
+        "index.html"
+    } else {
+        relative_path
+    };
+
+    let file = DISPLAY_ASSETS
+        .get_file(relative_path)
+        .or_else(|| DISPLAY_ASSETS.get_file("index.html"));
+
+    match file {
+        Some(file) => {
+            let mime = mime_guess::from_path(file.path())
+                .first_or_octet_stream()
+                .to_string();
+            Response::builder()
+                .header("Content-Type", mime)
+                .body(Cow::Borrowed(file.contents()))
+                .unwrap()
+        }
+        None => Response::builder()
+            .status(404)
+            .body(Cow::Borrowed(&b"not found"[..]))
+            .unwrap(),
+    }
+}
+
+/// Script injected before page load that opens a WebSocket back to the main
+/// process's bridge and patches the DOM on each `number-updated` message, so
+/// the display stays live without any polling.
+fn bridge_init_script(port: u16) -> String {
+    format!(
+        r#"
+        (function() {{
+            function connect() {{
+                var socket = new WebSocket("ws://127.0.0.1:{port}/");
+                socket.onmessage = function(event) {{
+                    try {{
+                        var message = JSON.parse(event.data);
+                        if (message.type === "number-updated") {{
+                            document.querySelectorAll("[data-proassist-number]").forEach(function(el) {{
+                                el.textContent = message.value;
+                            }});
+                        }}
+                    }} catch (e) {{
+                        console.error("proassist bridge: bad message", e);
+                    }}
+                }};
+                socket.onclose = function() {{ setTimeout(connect, 1000); }};
+            }}
+            connect();
+        }})();
+        "#,
+        port = port
+    )
+}
 
 fn main() -> ConfigResult<()> {
     let args: Vec<String> = env::args().collect();
     let url = if args.len() > 1 {
         args[1].clone()
     } else {
-        "https://tauri.app".to_string()
+        format!("{}://display/index.html", PROASSIST_PROTOCOL)
     };
+    let bridge_port: Option<u16> = args.get(2).and_then(|p| p.parse().ok());
+    let config = args.get(3).map(|arg| DisplayConfig::load(arg)).unwrap_or_default();
 
     let event_loop = EventLoopBuilder::new().build();
-    let window = WindowBuilder::new()
-        .with_title("ProAssist Display")
-        .build(&event_loop)
-        .unwrap();
+    let target_monitor = config.resolve_monitor(&event_loop);
+
+    let mut window_builder = WindowBuilder::new()
+        .with_title(config.title.clone().unwrap_or_else(|| "ProAssist Display".to_string()));
+
+    if let Some(decorations) = config.decorations {
+        window_builder = window_builder.with_decorations(decorations);
+    }
+    if let Some(resizable) = config.resizable {
+        window_builder = window_builder.with_resizable(resizable);
+    }
+    if let Some(always_on_top) = config.always_on_top {
+        window_builder = window_builder.with_always_on_top(always_on_top);
+    }
+    if let (Some(width), Some(height)) = (config.width, config.height) {
+        window_builder = window_builder.with_inner_size(LogicalSize::new(width, height));
+    }
+
+    if let (Some(x), Some(y)) = (config.x, config.y) {
+        window_builder = window_builder.with_position(LogicalPosition::new(x, y));
+    } else if let Some(monitor) = &target_monitor {
+        let position = monitor.position();
+        window_builder = window_builder.with_position(PhysicalPosition::new(position.x, position.y));
+    }
+
+    if config.fullscreen.unwrap_or(false) {
+        window_builder =
+            window_builder.with_fullscreen(Some(Fullscreen::Borderless(target_monitor.clone())));
+    }
+
+    let window = window_builder.build(&event_loop).unwrap();
 
     #[cfg(any(
         target_os = "windows",
@@ -41,6 +184,15 @@ fn main() -> ConfigResult<()> {
         WebViewBuilder::new_gtk(vbox)
     };
 
+    let mut builder = builder.with_custom_protocol(
+        PROASSIST_PROTOCOL.to_string(),
+        move |request: Request<Vec<u8>>| serve_asset(request.uri().path()),
+    );
+
+    if let Some(port) = bridge_port {
+        builder = builder.with_initialization_script(&bridge_init_script(port));
+    }
+
     let _webview = builder
         .with_url(&url)
         .unwrap()
@@ -0,0 +1,200 @@
+// bridge.rs runs a minimal loopback WebSocket server so the main process can
+// push live updates to the separate `display_window` process, which has no
+// other channel back into this app's event system.
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use log::error;
+
+const WEBSOCKET_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+/// Caps how long `broadcast`'s blocking write can stall the caller (an async
+/// Tauri command) if a display process stops reading -- crashed, frozen, or
+/// just slow. Past this, the write errors out and the client gets pruned.
+const CLIENT_WRITE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Holds the set of display processes currently connected over the bridge
+/// and the port they connect to. Managed as Tauri app state.
+#[derive(Clone)]
+pub struct DisplayBridge {
+    port: u16,
+    clients: Arc<Mutex<Vec<TcpStream>>>,
+}
+
+impl DisplayBridge {
+    /// Binds a loopback listener on an OS-assigned port and starts accepting
+    /// WebSocket connections in a background thread.
+    pub fn start() -> std::io::Result<DisplayBridge> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let port = listener.local_addr()?.port();
+        let clients: Arc<Mutex<Vec<TcpStream>>> = Arc::new(Mutex::new(Vec::new()));
+
+        let accept_clients = clients.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                match complete_handshake(stream) {
+                    Ok(stream) => accept_clients.lock().unwrap().push(stream),
+                    Err(e) => error!("WebSocket handshake with display process failed: {}", e),
+                }
+            }
+        });
+
+        Ok(DisplayBridge { port, clients })
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Broadcasts `payload` as a single WebSocket text frame to every
+    /// connected display process, dropping clients that have gone away.
+    pub fn broadcast(&self, payload: &str) {
+        let frame = encode_text_frame(payload);
+        let mut clients = self.clients.lock().unwrap();
+        clients.retain_mut(|client| client.write_all(&frame).is_ok());
+    }
+}
+
+fn complete_handshake(mut stream: TcpStream) -> std::io::Result<TcpStream> {
+    let mut buf = [0u8; 4096];
+    let n = stream.read(&mut buf)?;
+    let request = String::from_utf8_lossy(&buf[..n]);
+
+    let key = request
+        .lines()
+        .find_map(|line| line.strip_prefix("Sec-WebSocket-Key: "))
+        .ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, "missing Sec-WebSocket-Key")
+        })?
+        .trim();
+
+    let accept = websocket_accept_key(key);
+    let response = format!(
+        "HTTP/1.1 101 Switching Protocols\r\n\
+         Upgrade: websocket\r\n\
+         Connection: Upgrade\r\n\
+         Sec-WebSocket-Accept: {}\r\n\r\n",
+        accept
+    );
+    stream.write_all(response.as_bytes())?;
+    stream.set_write_timeout(Some(CLIENT_WRITE_TIMEOUT))?;
+    Ok(stream)
+}
+
+fn websocket_accept_key(key: &str) -> String {
+    let mut input = key.as_bytes().to_vec();
+    input.extend_from_slice(WEBSOCKET_GUID.as_bytes());
+    base64_encode(&sha1(&input))
+}
+
+fn encode_text_frame(payload: &str) -> Vec<u8> {
+    let bytes = payload.as_bytes();
+    let mut frame = vec![0x81]; // FIN + text opcode
+    if bytes.len() < 126 {
+        frame.push(bytes.len() as u8);
+    } else if bytes.len() < 65536 {
+        frame.push(126);
+        frame.extend_from_slice(&(bytes.len() as u16).to_be_bytes());
+    } else {
+        frame.push(127);
+        frame.extend_from_slice(&(bytes.len() as u64).to_be_bytes());
+    }
+    frame.extend_from_slice(bytes);
+    frame
+}
+
+// --- Minimal SHA-1 + base64, just enough to compute the handshake accept key. ---
+
+fn sha1(input: &[u8]) -> [u8; 20] {
+    let mut h: [u32; 5] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+
+    let mut message = input.to_vec();
+    let bit_len = (message.len() as u64) * 8;
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&bit_len.to_be_bytes());
+
+    for chunk in message.chunks(64) {
+        let mut w = [0u32; 80];
+        for (i, word) in chunk.chunks(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        }
+
+        let (mut a, mut b, mut c, mut d, mut e) = (h[0], h[1], h[2], h[3], h[4]);
+        for (i, word) in w.iter().enumerate() {
+            let (f, k) = match i {
+                0..=19 => ((b & c) | ((!b) & d), 0x5A827999u32),
+                20..=39 => (b ^ c ^ d, 0x6ED9EBA1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1BBCDC),
+                _ => (b ^ c ^ d, 0xCA62C1D6),
+            };
+            let temp = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(*word);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+
+        h[0] = h[0].wrapping_add(a);
+        h[1] = h[1].wrapping_add(b);
+        h[2] = h[2].wrapping_add(c);
+        h[3] = h[3].wrapping_add(d);
+        h[4] = h[4].wrapping_add(e);
+    }
+
+    let mut digest = [0u8; 20];
+    for (i, word) in h.iter().enumerate() {
+        digest[i * 4..i * 4 + 4].copy_from_slice(&word.to_be_bytes());
+    }
+    digest
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64_encode(input: &[u8]) -> String {
+    let mut out = String::with_capacity((input.len() + 2) / 3 * 4);
+    for chunk in input.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).copied();
+        let b2 = chunk.get(2).copied();
+
+        out.push(BASE64_ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(BASE64_ALPHABET[((b0 & 0x03) << 4 | b1.unwrap_or(0) >> 4) as usize] as char);
+        out.push(match b1 {
+            Some(b1) => BASE64_ALPHABET[((b1 & 0x0F) << 2 | b2.unwrap_or(0) >> 6) as usize] as char,
+            None => '=',
+        });
+        out.push(match b2 {
+            Some(b2) => BASE64_ALPHABET[(b2 & 0x3F) as usize] as char,
+            None => '=',
+        });
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // RFC 6455 section 1.3 handshake example.
+    #[test]
+    fn websocket_accept_key_matches_rfc6455_vector() {
+        let key = "dGhlIHNhbXBsZSBub25jZQ==";
+        assert_eq!(websocket_accept_key(key), "s3pPLMBiTxaQ9kYGzzhZRbK+xOo=");
+    }
+}
@@ -0,0 +1,29 @@
+// display_config.rs defines the window-attributes config shared between the
+// main process (which builds it) and the standalone `display_window`
+// process (which parses and applies it). Included by path into both binary
+// crates since there is no shared lib target for them to depend on.
+use serde::{Deserialize, Serialize};
+
+/// Which monitor a standalone display window should be placed on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MonitorTarget {
+    Index(usize),
+    Name(String),
+}
+
+/// Window attributes for the standalone `display_window` process, accepted
+/// either as an inline JSON CLI arg or as a path to a JSON config file.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DisplayConfig {
+    pub title: Option<String>,
+    pub decorations: Option<bool>,
+    pub resizable: Option<bool>,
+    pub fullscreen: Option<bool>,
+    pub always_on_top: Option<bool>,
+    pub monitor: Option<MonitorTarget>,
+    pub width: Option<f64>,
+    pub height: Option<f64>,
+    pub x: Option<f64>,
+    pub y: Option<f64>,
+}
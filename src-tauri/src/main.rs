@@ -1,6 +1,18 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod bridge;
+mod display_config;
+mod window_commands;
+mod window_state;
+
+use bridge::DisplayBridge;
+use tauri::Manager;
+use window_commands::{
+    get_monitors, monitor_from_point, open_dialog, save_window_state, restore_window_state,
+    update_second_screen_number,
+};
+
 // Learn more about Tauri commands at https://tauri.app/v1/guides/features/command
 #[tauri::command]
 fn greet(name: &str) -> String {
@@ -26,9 +38,20 @@ fn write_text_to_file(file_path: String, content: String) -> Result<(), String>
 
 fn main() {
     tauri::Builder::default()
+        .setup(|app| {
+            let bridge = DisplayBridge::start().map_err(|e| e.to_string())?;
+            app.manage(bridge);
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             greet,
-            write_text_to_file
+            write_text_to_file,
+            get_monitors,
+            monitor_from_point,
+            open_dialog,
+            save_window_state,
+            restore_window_state,
+            update_second_screen_number
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -4,6 +4,10 @@ use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Emitter, Manager, WebviewWindowBuilder};
 use std::process::Command;
 
+use crate::bridge::DisplayBridge;
+use crate::display_config::{DisplayConfig, MonitorTarget};
+use crate::window_state::{self, StateFlags};
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MonitorInfo {
     pub name: String,
@@ -12,66 +16,161 @@ pub struct MonitorInfo {
     pub scale_factor: f64,
 }
 
+fn to_monitor_info(monitor: &tauri::Monitor, index: usize) -> MonitorInfo {
+    let name = monitor.name()
+        .map(|n| n.to_string())
+        .unwrap_or_else(|| format!("Monitor {}", index + 1));
+
+    let position = monitor.position();
+    let size = monitor.size();
+
+    MonitorInfo {
+        name,
+        position: (position.x, position.y),
+        size: (size.width, size.height),
+        scale_factor: monitor.scale_factor(),
+    }
+}
+
 #[tauri::command]
 pub async fn get_monitors(app_handle: AppHandle) -> Result<Vec<MonitorInfo>, String> {
     let monitors = app_handle
         .available_monitors()
         .map_err(|e| format!("Failed to get monitors: {}", e))?;
 
-    let monitor_infos: Vec<MonitorInfo> = monitors
+    let monitor_infos = monitors
         .iter()
         .enumerate()
-        .map(|(index, monitor)| {
-            let name = monitor.name()
-                .map(|n| n.to_string())
-                .unwrap_or_else(|| format!("Monitor {}", index + 1));
-            
-            let position = monitor.position();
-            let size = monitor.size();
-            let scale_factor = monitor.scale_factor();
-
-            MonitorInfo {
-                name,
-                position: (position.x, position.y),
-                size: (size.width, size.height),
-                scale_factor,
-            }
-        })
+        .map(|(index, monitor)| to_monitor_info(monitor, index))
         .collect();
 
     Ok(monitor_infos)
 }
 
+/// Finds the monitor whose physical bounds contain `(x, y)`, treating the
+/// point as logical coordinates and converting with each candidate monitor's
+/// own `scale_factor` before testing.
+fn monitor_at_point(monitors: &[tauri::Monitor], x: f64, y: f64) -> Option<usize> {
+    monitors.iter().position(|monitor| {
+        let scale_factor = monitor.scale_factor();
+        let physical_x = x * scale_factor;
+        let physical_y = y * scale_factor;
+        let position = monitor.position();
+        let size = monitor.size();
+
+        physical_x >= position.x as f64
+            && physical_x < position.x as f64 + size.width as f64
+            && physical_y >= position.y as f64
+            && physical_y < position.y as f64 + size.height as f64
+    })
+}
+
+#[tauri::command]
+pub async fn monitor_from_point(
+    app_handle: AppHandle,
+    x: f64,
+    y: f64,
+) -> Result<Option<MonitorInfo>, String> {
+    let monitors = app_handle
+        .available_monitors()
+        .map_err(|e| format!("Failed to get monitors: {}", e))?;
+
+    Ok(monitor_at_point(&monitors, x, y).map(|index| to_monitor_info(&monitors[index], index)))
+}
+
 #[tauri::command]
 pub async fn open_dialog(
     app_handle: AppHandle,
     _webview_window: tauri::WebviewWindow,
     dialog_window: String,
     monitor_index: Option<usize>,
+    point: Option<(f64, f64)>,
 ) -> Result<(), String> {
+    // Monitors are only needed to resolve a drag-to-place point or to look up
+    // the second screen's saved/fallback target; skip the call otherwise.
+    let monitors = if point.is_some() || dialog_window == "second-screen" {
+        Some(
+            app_handle
+                .available_monitors()
+                .map_err(|e| format!("Failed to get monitors: {}", e))?,
+        )
+    } else {
+        None
+    };
+
+    // A drag-to-place point takes priority over an explicit monitor index
+    // when both are given.
+    let monitor_index = match (point, &monitors) {
+        (Some((x, y)), Some(monitors)) => monitor_at_point(monitors, x, y).or(monitor_index),
+        _ => monitor_index,
+    };
+
     // Check if this is the second screen request
     if dialog_window == "second-screen" {
-        // Use separate binary for second screen as requested
-        let url = "http://localhost:1420/display.html";
-        
+        let monitors = monitors.expect("fetched above for second-screen");
+        let dialog_label = format!("dialog-{}", dialog_window);
+
+        // Neither an explicit index nor a drag-to-place point: fall back to
+        // whichever monitor the operator last picked for this label. The
+        // display process is a separate native window, not a Tauri-managed
+        // WebviewWindow, so `track_window`/`restore_window_state` can't reach
+        // it -- this is the equivalent persisted lookup for that case.
+        let monitor_index = monitor_index.or_else(|| {
+            window_state::saved_monitor_name(&app_handle, &dialog_label)
+                .and_then(|name| monitors.iter().position(|m| m.name().as_deref() == Some(name.as_str())))
+        });
+
+        // Persist whichever monitor we end up targeting so the operator's
+        // choice survives a relaunch.
+        let monitor_name = monitor_index
+            .and_then(|index| monitors.get(index))
+            .and_then(|monitor| monitor.name().map(|n| n.to_string()));
+        window_state::record_monitor_for_label(&app_handle, &dialog_label, monitor_name)?;
+
+        // Use separate binary for second screen as requested. Its assets are
+        // bundled in and served over the proassist:// custom protocol, so
+        // the same URL works in both dev and release builds.
+        let url = "proassist://display/index.html";
+
+        // The display process is launched separately, so it gets live
+        // updates over the local bridge rather than Tauri's in-process
+        // event system; pass it the port to connect back to.
+        let bridge_port = app_handle.state::<DisplayBridge>().port().to_string();
+
+        // Borderless, always-on-top and fullscreen on the monitor the
+        // operator picked (via monitor_index, a drag-to-place point, or the
+        // saved fallback above) -- this is the in-process sizing/fullscreen
+        // behavior the old same-process second screen window used to get,
+        // now carried over to the spawned process.
+        let config = DisplayConfig {
+            decorations: Some(false),
+            resizable: Some(false),
+            fullscreen: Some(true),
+            always_on_top: Some(true),
+            monitor: monitor_index.map(MonitorTarget::Index),
+            ..Default::default()
+        };
+        let config_json = serde_json::to_string(&config)
+            .map_err(|e| format!("Failed to serialize display config: {}", e))?;
+
         // In development, we use cargo run --bin
         #[cfg(debug_assertions)]
         let _status = Command::new("cargo")
-            .args(["run", "--bin", "display_window", "--", url])
+            .args(["run", "--bin", "display_window", "--", url, &bridge_port, &config_json])
             .spawn()
             .map_err(|e| format!("Failed to spawn display process: {}", e))?;
 
         #[cfg(not(debug_assertions))]
         {
-            // In production, we expect the binary to be bundled side-by-side or handled differently.
-            // For now, this placeholder reminds us to configure externalBin.
-            // Assuming the binary is named 'display_window' (or display_window.exe on Windows) next to the main executable.
+            // In production, the display_window binary is bundled side-by-side
+            // with the main executable (see externalBin in tauri.conf.json).
             let current_exe = std::env::current_exe().map_err(|e| e.to_string())?;
             let current_dir = current_exe.parent().ok_or("Failed to get current dir")?;
-            let display_binary = current_dir.join("display_window.exe"); // Windows assumption
-            
+            let display_binary =
+                current_dir.join(format!("display_window{}", std::env::consts::EXE_SUFFIX));
+
             Command::new(display_binary)
-                .arg(url)
+                .args([url, &bridge_port, &config_json])
                 .spawn()
                 .map_err(|e| format!("Failed to spawn display process: {}", e))?;
         }
@@ -98,20 +197,8 @@ async fn open_dialog_impl(
             error!("Error focusing the dialog window: {:?}", e);
         }
     } else {
-        // Check if this is the second screen window
-        let is_second_screen = dialog_window == "second-screen";
-        
-        // Define the URL to load.
-        // For the second screen, we load the specialized lightweight HTML file to avoid React app conflicts.
-        let url = if is_second_screen {
-            tauri::WebviewUrl::App("window.html".into())
-        } else {
-            tauri::WebviewUrl::default()
-        };
-        
-        let mut builder = WebviewWindowBuilder::new(&handle, &dialog_label, url)
+        let mut builder = WebviewWindowBuilder::new(&handle, &dialog_label, tauri::WebviewUrl::default())
             .title(title)
-            .decorations(!is_second_screen) // Borderless for second screen
             .inner_size(800.0, 600.0)
             .min_inner_size(800.0, 600.0);
 
@@ -119,60 +206,28 @@ async fn open_dialog_impl(
         if let Some(index) = monitor_index {
             if let Ok(monitors) = handle.available_monitors() {
                 if let Some(monitor) = monitors.get(index) {
-                    let monitor_position = monitor.position();
-                    let monitor_size = monitor.size();
-                    let scale_factor = monitor.scale_factor();
-                    
-                    if is_second_screen {
-                        // For second screen: set size to match monitor and position at monitor origin
-                        let monitor_width_logical = monitor_size.width as f64 / scale_factor;
-                        let monitor_height_logical = monitor_size.height as f64 / scale_factor;
-                        let monitor_x_logical = monitor_position.x as f64 / scale_factor;
-                        let monitor_y_logical = monitor_position.y as f64 / scale_factor;
-                        
-                        builder = builder
-                            .inner_size(monitor_width_logical, monitor_height_logical)
-                            .position(monitor_x_logical, monitor_y_logical);
-                    } else {
-                        // For other windows: center on monitor
-                        let window_width = 800.0;
-                        let window_height = 600.0;
-                        
-                        let monitor_x_logical = monitor_position.x as f64 / scale_factor;
-                        let monitor_y_logical = monitor_position.y as f64 / scale_factor;
-                        let monitor_width_logical = monitor_size.width as f64 / scale_factor;
-                        let monitor_height_logical = monitor_size.height as f64 / scale_factor;
-                        
-                        let x = monitor_x_logical + (monitor_width_logical / 2.0) - (window_width / 2.0);
-                        let y = monitor_y_logical + (monitor_height_logical / 2.0) - (window_height / 2.0);
-                        
-                        builder = builder.position(x, y);
-                    }
+                    // Center on the chosen monitor before the window is ever
+                    // shown, rather than letting it flash at the default spot
+                    // and snap to center afterward.
+                    builder = window_state::center_on_monitor(monitor, 800.0, 600.0, builder);
                 } else {
-                    // Fallback to center if monitor index is invalid
-                    builder = builder.center();
+                    // Fallback to center on the primary monitor if the index is invalid
+                    builder = window_state::center_on_primary_monitor(&handle, 800.0, 600.0, builder);
                 }
             } else {
-                // Fallback to center if monitors can't be retrieved
-                builder = builder.center();
+                // Fallback to center on the primary monitor if monitors can't be retrieved
+                builder = window_state::center_on_primary_monitor(&handle, 800.0, 600.0, builder);
             }
         } else {
-            // Default to center if no monitor is selected
-            builder = builder.center();
+            // No explicit monitor was requested: restore the window's saved
+            // geometry/monitor, falling back to center if nothing was saved
+            // or its monitor is gone.
+            builder = window_state::restore_window_state(&handle, &dialog_label, StateFlags::ALL, builder);
         }
 
         // Build the window
-        let builder_res = builder.build();
-        
-        match builder_res {
-            Ok(window) => {
-                // Set fullscreen for second screen
-                if is_second_screen {
-                    if let Err(e) = window.set_fullscreen(true) {
-                        error!("Failed to set fullscreen: {:?}", e);
-                    }
-                }
-            },
+        match builder.build() {
+            Ok(window) => window_state::track_window(&handle, &window),
             Err(e) => {
                 error!("Failed to build window: {:?}", e);
                 return Err(format!("Failed to build window: {}", e));
@@ -182,17 +237,39 @@ async fn open_dialog_impl(
     Ok(())
 }
 
+#[tauri::command]
+pub async fn save_window_state(app_handle: AppHandle, label: String) -> Result<(), String> {
+    let window = app_handle
+        .get_webview_window(&label)
+        .ok_or_else(|| format!("Window '{}' not found", label))?;
+    window_state::record_window_state(&app_handle, &window)
+}
+
+#[tauri::command]
+pub async fn restore_window_state(app_handle: AppHandle, label: String) -> Result<(), String> {
+    let window = app_handle
+        .get_webview_window(&label)
+        .ok_or_else(|| format!("Window '{}' not found", label))?;
+    window_state::apply_saved_state(&app_handle, &window)
+}
+
 #[tauri::command]
 pub async fn update_second_screen_number(
     app_handle: AppHandle,
     number: String,
 ) -> Result<(), String> {
-    // Emit event to all windows, but specifically the second screen will listen
+    // Emit to in-process windows (if any host the second screen directly)...
     let windows = app_handle.webview_windows();
     for (_, window) in windows.iter() {
         if let Err(e) = window.emit("number-updated", &number) {
             error!("Failed to emit event to window {}: {:?}", window.label(), e);
         }
     }
+
+    // ...and push it over the bridge so the separate display_window process,
+    // which never sees in-process Tauri events, gets it too.
+    let payload = serde_json::json!({ "type": "number-updated", "value": number }).to_string();
+    app_handle.state::<DisplayBridge>().broadcast(&payload);
+
     Ok(())
 }
@@ -0,0 +1,312 @@
+// window_state.rs persists and restores per-window geometry across app restarts.
+use std::collections::HashMap;
+use std::fs;
+use std::ops::{BitOr, BitOrAssign};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use log::{error, warn};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, WebviewWindow, WebviewWindowBuilder};
+
+const STATE_FILENAME: &str = "window-state.json";
+
+/// Minimum gap between persisted writes triggered by Moved/Resized events, so
+/// dragging a window doesn't hammer disk on every pixel of movement.
+const PERSIST_THROTTLE: Duration = Duration::from_millis(500);
+
+/// Which parts of a window's geometry get saved/restored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StateFlags(u8);
+
+impl StateFlags {
+    pub const POSITION: StateFlags = StateFlags(1 << 0);
+    pub const SIZE: StateFlags = StateFlags(1 << 1);
+    pub const FULLSCREEN: StateFlags = StateFlags(1 << 2);
+    pub const MONITOR: StateFlags = StateFlags(1 << 3);
+    pub const ALL: StateFlags = StateFlags(
+        Self::POSITION.0 | Self::SIZE.0 | Self::FULLSCREEN.0 | Self::MONITOR.0,
+    );
+
+    pub fn contains(self, other: StateFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for StateFlags {
+    type Output = StateFlags;
+    fn bitor(self, rhs: StateFlags) -> StateFlags {
+        StateFlags(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for StateFlags {
+    fn bitor_assign(&mut self, rhs: StateFlags) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// A single labeled window's persisted geometry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WindowState {
+    pub x: i32,
+    pub y: i32,
+    pub width: f64,
+    pub height: f64,
+    pub fullscreen: bool,
+    pub monitor_name: Option<String>,
+}
+
+type WindowStateMap = HashMap<String, WindowState>;
+
+fn state_file_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app_handle
+        .path()
+        .app_config_dir()
+        .map_err(|e| format!("Failed to resolve app config dir: {}", e))?;
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create app config dir: {}", e))?;
+    Ok(dir.join(STATE_FILENAME))
+}
+
+fn load_all(app_handle: &AppHandle) -> WindowStateMap {
+    let path = match state_file_path(app_handle) {
+        Ok(path) => path,
+        Err(e) => {
+            warn!("Could not resolve window state path: {}", e);
+            return WindowStateMap::new();
+        }
+    };
+
+    match fs::read_to_string(&path) {
+        Ok(contents) => serde_json::from_str(&contents).unwrap_or_default(),
+        Err(_) => WindowStateMap::new(),
+    }
+}
+
+fn save_all(app_handle: &AppHandle, states: &WindowStateMap) -> Result<(), String> {
+    let path = state_file_path(app_handle)?;
+    let json = serde_json::to_string_pretty(states)
+        .map_err(|e| format!("Failed to serialize window state: {}", e))?;
+    fs::write(&path, json).map_err(|e| format!("Failed to write window state: {}", e))
+}
+
+/// Records `window`'s current outer position, inner size, fullscreen flag, and
+/// the monitor it sits on, merging it into the persisted state map.
+pub fn record_window_state(app_handle: &AppHandle, window: &WebviewWindow) -> Result<(), String> {
+    let label = window.label().to_string();
+
+    let position = window
+        .outer_position()
+        .map_err(|e| format!("Failed to read window position: {}", e))?;
+    let size = window
+        .inner_size()
+        .map_err(|e| format!("Failed to read window size: {}", e))?;
+    let scale_factor = window.scale_factor().unwrap_or(1.0);
+    let fullscreen = window.is_fullscreen().unwrap_or(false);
+    let monitor_name = window
+        .current_monitor()
+        .ok()
+        .flatten()
+        .and_then(|monitor| monitor.name().map(|n| n.to_string()));
+
+    let mut states = load_all(app_handle);
+    states.insert(
+        label,
+        WindowState {
+            x: (position.x as f64 / scale_factor) as i32,
+            y: (position.y as f64 / scale_factor) as i32,
+            width: size.width as f64 / scale_factor,
+            height: size.height as f64 / scale_factor,
+            fullscreen,
+            monitor_name,
+        },
+    );
+    save_all(app_handle, &states)
+}
+
+/// Attaches close/move/resize listeners to `window` that persist its state
+/// whenever the geometry changes. Moved/Resized fire many times per drag, so
+/// those are throttled to at most one write per `PERSIST_THROTTLE`; closing
+/// always persists immediately so the final geometry is never dropped.
+pub fn track_window(app_handle: &AppHandle, window: &WebviewWindow) {
+    let app_handle = app_handle.clone();
+    let window_clone = window.clone();
+    let last_persisted: Arc<Mutex<Option<Instant>>> = Arc::new(Mutex::new(None));
+
+    window.on_window_event(move |event| match event {
+        tauri::WindowEvent::Moved(_) | tauri::WindowEvent::Resized(_) => {
+            let mut last_persisted = last_persisted.lock().unwrap();
+            let due = match *last_persisted {
+                Some(last) => last.elapsed() >= PERSIST_THROTTLE,
+                None => true,
+            };
+            if !due {
+                return;
+            }
+            *last_persisted = Some(Instant::now());
+            drop(last_persisted);
+
+            if let Err(e) = record_window_state(&app_handle, &window_clone) {
+                error!("Failed to persist window state: {:?}", e);
+            }
+        }
+        tauri::WindowEvent::CloseRequested { .. } => {
+            if let Err(e) = record_window_state(&app_handle, &window_clone) {
+                error!("Failed to persist window state on close: {:?}", e);
+            }
+        }
+        _ => {}
+    });
+}
+
+/// Records just the monitor chosen for `label`, independent of full geometry
+/// tracking. Windows like the standalone second-screen process are never a
+/// Tauri-managed `WebviewWindow`, so they can't go through `track_window`;
+/// this lets their caller persist the operator's choice directly.
+pub fn record_monitor_for_label(
+    app_handle: &AppHandle,
+    label: &str,
+    monitor_name: Option<String>,
+) -> Result<(), String> {
+    let mut states = load_all(app_handle);
+    states
+        .entry(label.to_string())
+        .or_insert_with(|| WindowState {
+            x: 0,
+            y: 0,
+            width: 800.0,
+            height: 600.0,
+            fullscreen: false,
+            monitor_name: None,
+        })
+        .monitor_name = monitor_name;
+    save_all(app_handle, &states)
+}
+
+/// Looks up the monitor last recorded for `label`, via
+/// `record_monitor_for_label` or `record_window_state`.
+pub fn saved_monitor_name(app_handle: &AppHandle, label: &str) -> Option<String> {
+    load_all(app_handle).get(label).and_then(|s| s.monitor_name.clone())
+}
+
+/// Returns true if `state`'s saved monitor is still among the monitors
+/// currently available, so callers can fall back to center instead of
+/// restoring onto a monitor that's been unplugged. Always true when the
+/// caller isn't asking to restore by monitor, or nothing was saved for it.
+fn monitor_still_present(app_handle: &AppHandle, state: &WindowState, flags: StateFlags) -> bool {
+    if !flags.contains(StateFlags::MONITOR) {
+        return true;
+    }
+    let Some(name) = &state.monitor_name else {
+        return true;
+    };
+    app_handle
+        .available_monitors()
+        .ok()
+        .map(|monitors| monitors.iter().any(|m| m.name().as_deref() == Some(name.as_str())))
+        .unwrap_or(false)
+}
+
+/// Applies the saved record for `window`'s label directly onto an
+/// already-open window, for callers that want to trigger a restore without
+/// recreating the window.
+pub fn apply_saved_state(app_handle: &AppHandle, window: &WebviewWindow) -> Result<(), String> {
+    let states = load_all(app_handle);
+    let Some(state) = states.get(window.label()) else {
+        return Ok(());
+    };
+
+    if !monitor_still_present(app_handle, state, StateFlags::ALL) {
+        warn!(
+            "Saved monitor '{:?}' for window '{}' is no longer available; skipping restore",
+            state.monitor_name,
+            window.label()
+        );
+        return Ok(());
+    }
+
+    window
+        .set_position(tauri::LogicalPosition::new(state.x as f64, state.y as f64))
+        .map_err(|e| format!("Failed to restore window position: {}", e))?;
+    window
+        .set_size(tauri::LogicalSize::new(state.width, state.height))
+        .map_err(|e| format!("Failed to restore window size: {}", e))?;
+    window
+        .set_fullscreen(state.fullscreen)
+        .map_err(|e| format!("Failed to restore window fullscreen state: {}", e))?;
+
+    Ok(())
+}
+
+/// Positions `builder`'s window centered on `monitor` *before* it is shown,
+/// so it never flashes at a default spot and snaps to center afterward the
+/// way a post-show `.center()` can. All math is done in physical pixels,
+/// then converted back to logical for the builder.
+pub fn center_on_monitor(
+    monitor: &tauri::Monitor,
+    inner_width_logical: f64,
+    inner_height_logical: f64,
+    builder: WebviewWindowBuilder,
+) -> WebviewWindowBuilder {
+    let scale_factor = monitor.scale_factor();
+    let monitor_position = monitor.position();
+    let monitor_size = monitor.size();
+
+    let window_width_physical = inner_width_logical * scale_factor;
+    let window_height_physical = inner_height_logical * scale_factor;
+
+    let x_physical = monitor_position.x as f64 + (monitor_size.width as f64 - window_width_physical) / 2.0;
+    let y_physical = monitor_position.y as f64 + (monitor_size.height as f64 - window_height_physical) / 2.0;
+
+    builder.position(x_physical / scale_factor, y_physical / scale_factor)
+}
+
+/// Centers `builder` on the primary monitor (or falls back to the builder's
+/// own `.center()` if there isn't one), for callers with no specific target
+/// monitor in mind.
+pub fn center_on_primary_monitor(
+    app_handle: &AppHandle,
+    inner_width_logical: f64,
+    inner_height_logical: f64,
+    builder: WebviewWindowBuilder,
+) -> WebviewWindowBuilder {
+    match app_handle.primary_monitor() {
+        Ok(Some(monitor)) => center_on_monitor(&monitor, inner_width_logical, inner_height_logical, builder),
+        _ => builder.center(),
+    }
+}
+
+/// Looks up the saved record for `label` and seeds `builder` with its
+/// position/size, matching the saved monitor name against the monitors
+/// currently available. Falls back to centering the default 800x600 window on
+/// the primary monitor when nothing was saved or the saved monitor is no
+/// longer present.
+pub fn restore_window_state(
+    app_handle: &AppHandle,
+    label: &str,
+    flags: StateFlags,
+    mut builder: WebviewWindowBuilder,
+) -> WebviewWindowBuilder {
+    let states = load_all(app_handle);
+    let Some(state) = states.get(label) else {
+        return center_on_primary_monitor(app_handle, 800.0, 600.0, builder);
+    };
+
+    if !monitor_still_present(app_handle, state, flags) {
+        warn!("Saved monitor '{:?}' for window '{}' is no longer available", state.monitor_name, label);
+        return center_on_primary_monitor(app_handle, 800.0, 600.0, builder);
+    }
+
+    if flags.contains(StateFlags::SIZE) {
+        builder = builder.inner_size(state.width, state.height);
+    }
+    if flags.contains(StateFlags::POSITION) {
+        builder = builder.position(state.x as f64, state.y as f64);
+    }
+    if flags.contains(StateFlags::FULLSCREEN) && state.fullscreen {
+        builder = builder.fullscreen(true);
+    }
+
+    builder
+}